@@ -42,6 +42,7 @@ extern crate std;
 use core::{
     cmp::PartialEq,
     convert::TryFrom,
+    marker::PhantomData,
     ops::{Add, AddAssign, Div},
 };
 
@@ -136,6 +137,7 @@ pub struct GenericSequence<I, F, const D: usize> {
     b: I,
     d: [I; D],
     r: [F; D],
+    back: usize,
 }
 
 impl<I, F, const D: usize> GenericSequence<I, F, D>
@@ -163,25 +165,58 @@ where
             b: base,
             d: [I::from(0u8); D],
             r: [F::from(0.0f32); D],
+            back: 0,
         }
     }
 
-    fn pos(&self) -> Option<usize> {
-        self.d
-            .iter()
-            .zip(1..)
-            .map(|(v, i)| usize::from(*v).checked_mul(i))
-            .try_fold(0usize, |acc, v| acc.checked_add(v?))
+    /// Returns the number at `index` of the sequence, computed directly from
+    /// the digit expansion of `index`. Used by [`next_back`] to yield entries
+    /// from the end of the sequence, where the incremental recurrence driving
+    /// [`next`] cannot be run in reverse.
+    ///
+    /// [`next`]: Iterator::next
+    /// [`next_back`]: DoubleEndedIterator::next_back
+    fn number(&self, mut index: usize) -> F {
+        let b = usize::from(self.b);
+        let mut digits = [I::from(0u8); D];
+        let mut len = 0;
+        while index > 0 {
+            digits[len] = I::try_from(index % b).ok().unwrap();
+            index /= b;
+            len += 1;
+        }
+        let mut result = F::from(0.0f32);
+        for i in (0..len).rev() {
+            result = (F::from(digits[i]) + result) / F::from(self.b);
+        }
+        result
     }
 
-    fn max(&self) -> Option<usize> {
+    fn pos(&self) -> usize {
+        let b = usize::from(self.b);
+        let mut factor = 1usize;
+        let mut acc = 0usize;
+        for (i, v) in self.d.iter().enumerate() {
+            acc = acc.saturating_add(usize::from(*v).saturating_mul(factor));
+            if i + 1 < self.d.len() {
+                factor = factor.saturating_mul(b);
+            }
+        }
+        acc
+    }
+
+    /// The maximum index of the sequence, `bᴰ - 1`. When `bᴰ` is not
+    /// representable in a `usize` the window is capped at `usize::MAX`, so the
+    /// cursor can still walk the finite range `[1, usize::MAX]`.
+    fn max(&self) -> usize {
         u32::try_from(self.d.len())
             .ok()
-            .and_then(|len| usize::from(self.b).checked_pow(len).map(|v| v - 1))
+            .and_then(|len| usize::from(self.b).checked_pow(len))
+            .map_or(usize::MAX, |v| v - 1)
     }
 
-    fn remaining(&self) -> Option<usize> {
-        Some(self.max()? - self.pos()?)
+    fn remaining(&self) -> usize {
+        self.max().saturating_sub(self.pos()).saturating_sub(self.back)
     }
 }
 
@@ -196,6 +231,10 @@ where
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
+        if self.pos().saturating_add(self.back) >= GenericSequence::max(self) {
+            return None;
+        }
+
         let mut l = 0;
 
         self.d[l] += I::from(1u8);
@@ -220,35 +259,27 @@ where
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
-        if let Some(remaining) = self.remaining() {
-            (remaining, Some(remaining))
-        } else {
-            (0, None)
-        }
+        let remaining = self.remaining();
+        (remaining, Some(remaining))
     }
 
     #[inline]
     fn count(self) -> usize {
-        if let Some(remaining) = self.remaining() {
-            remaining
-        } else {
-            panic!("attempt to add with overflow")
-        }
+        self.remaining()
     }
 
     #[inline]
     fn last(mut self) -> Option<Self::Item> {
-        if let Some(remaining) = self.remaining() {
-            self.nth(remaining - 1)
-        } else {
-            self.fold(None, |_, v| Some(v))
+        match self.remaining() {
+            0 => None,
+            remaining => self.nth(remaining - 1),
         }
     }
 
     #[inline]
     fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
         if n > 50 {
-            if let Some(mut n) = self.pos().and_then(|p| n.checked_add(p)) {
+            if let Some(mut n) = n.checked_add(self.pos()) {
                 self.d.iter_mut().for_each(|v| *v = I::from(0u8));
                 self.r.iter_mut().for_each(|v| *v = F::from(0.0f32));
                 let mut last = 0;
@@ -274,12 +305,523 @@ where
     }
 }
 
+impl<I, F, const D: usize> DoubleEndedIterator for GenericSequence<I, F, D>
+where
+    I: AddAssign + Copy + From<u8> + PartialEq + TryFrom<usize>,
+    f64: From<I>,
+    usize: From<I>,
+    F: Add<Output = F> + Copy + Div<Output = F> + From<I> + From<f32>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let max = GenericSequence::max(self);
+        if self.pos().saturating_add(self.back) >= max {
+            return None;
+        }
+        let index = max - self.back;
+        self.back += 1;
+        Some(self.number(index))
+    }
+}
+
+impl<I, F, const D: usize> ExactSizeIterator for GenericSequence<I, F, D>
+where
+    I: AddAssign + Copy + From<u8> + PartialEq + TryFrom<usize>,
+    f64: From<I>,
+    usize: From<I>,
+    F: Add<Output = F> + Copy + Div<Output = F> + From<I> + From<f32>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.remaining()
+    }
+}
+
 /// A type alias for [`GenericSequence`] with defaults.
 pub type Sequence = GenericSequence<u16, f64, 20>;
 
+/// An iterator yielding points in `N`-dimensional space, with each component
+/// drawn from an independent Halton sequence. This turns the common "sample
+/// points in n-D space" pattern shown in the module documentation into a
+/// first-class API, rather than manually zipping several [`Sequence`]s.
+///
+/// By default the component sequences use the first `N` prime numbers (2, 3,
+/// 5, 7, 11, …) as their bases, a standard choice that keeps the dimensions
+/// uncorrelated. Callers that want to pick their own coprime bases can use
+/// [`with_bases`].
+///
+/// The point stream ends when the shortest component sequence ends.
+///
+/// [`with_bases`]: Points::with_bases
+///
+/// # Examples
+///
+/// ```
+/// use halton::Points;
+///
+/// let mut points = Points::<f64, 2>::new();
+///
+/// assert_eq!(Some([0.5, 0.3333333333333333]), points.next());
+/// assert_eq!(Some([0.25, 0.6666666666666666]), points.next());
+/// ```
+#[derive(Clone)]
+pub struct Points<F, const N: usize> {
+    sequences: [GenericSequence<u16, F, 20>; N],
+}
+
+impl<F, const N: usize> Points<F, N>
+where
+    F: Add<Output = F> + Copy + Div<Output = F> + From<u16> + From<f32>,
+{
+    /// Constructs a new [`Points`] iterator seeded with the first `N` prime
+    /// numbers as bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use halton::Points;
+    /// let mut points = Points::<f64, 3>::new();
+    ///
+    /// assert_eq!(Some([0.5, 0.3333333333333333, 0.2]), points.next());
+    /// ```
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_bases(first_primes())
+    }
+
+    /// Constructs a new [`Points`] iterator using the given `bases`. The bases
+    /// should be pairwise coprime (for example distinct primes) to keep the
+    /// dimensions uncorrelated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use halton::Points;
+    /// let mut points = Points::<f64, 2>::with_bases([2, 3]);
+    ///
+    /// assert_eq!(Some([0.5, 0.3333333333333333]), points.next());
+    /// ```
+    #[inline]
+    pub fn with_bases(bases: [u16; N]) -> Self {
+        Points {
+            sequences: core::array::from_fn(|i| GenericSequence::new(bases[i])),
+        }
+    }
+}
+
+impl<F, const N: usize> Default for Points<F, N>
+where
+    F: Add<Output = F> + Copy + Div<Output = F> + From<u16> + From<f32>,
+{
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F, const N: usize> Iterator for Points<F, N>
+where
+    F: Add<Output = F> + Copy + Div<Output = F> + From<u16> + From<f32>,
+{
+    type Item = [F; N];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut point = [F::from(0.0f32); N];
+        for (p, seq) in point.iter_mut().zip(self.sequences.iter_mut()) {
+            *p = seq.next()?;
+        }
+        Some(point)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let mut lower = usize::MAX;
+        let mut upper = None;
+        for seq in self.sequences.iter() {
+            let (l, u) = seq.size_hint();
+            lower = lower.min(l);
+            if let Some(u) = u {
+                upper = Some(upper.map_or(u, |cur: usize| cur.min(u)));
+            }
+        }
+        (lower, upper)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let mut point = [F::from(0.0f32); N];
+        for (p, seq) in point.iter_mut().zip(self.sequences.iter_mut()) {
+            *p = seq.nth(n)?;
+        }
+        Some(point)
+    }
+}
+
+/// Returns the first `N` prime numbers.
+fn first_primes<const N: usize>() -> [u16; N] {
+    let mut primes = [0u16; N];
+    let mut count = 0;
+    let mut candidate = 2u16;
+    while count < N {
+        if is_prime(candidate) {
+            primes[count] = candidate;
+            count += 1;
+        }
+        candidate += 1;
+    }
+    primes
+}
+
+/// Returns `true` if `n` is prime, by trial division.
+fn is_prime(n: u16) -> bool {
+    let n = u32::from(n);
+    if n < 2 {
+        return false;
+    }
+    let mut i = 2u32;
+    while i * i <= n {
+        if n % i == 0 {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// An iterator generating a scrambled Halton sequence for base `B`.
+///
+/// Plain Halton sequences correlate badly between dimensions when the base is
+/// large, showing up as diagonal streaks in the sample points. Scrambling
+/// applies a fixed permutation π of the digit alphabet `{0, …, B - 1}` to each
+/// radix-`B` digit before it is accumulated, so the radical inverse becomes
+/// Σ π(dᵢ)·B^(−i−1) rather than Σ dᵢ·B^(−i−1). This breaks up the correlation
+/// while keeping the sequence deterministic and equidistributed.
+///
+/// The permutation must fix `0 → 0` (so outputs stay in the range > 0 and < 1)
+/// and be a true bijection of `{0, …, B - 1}` (so the sequence stays
+/// equidistributed). All of the provided constructors uphold these invariants;
+/// [`with_permutation`] checks them.
+///
+/// The scrambled values are computed from the digit expansion directly — as in
+/// [`number`] — rather than from the incremental recurrence driving
+/// [`GenericSequence`], which relies on raw digit arithmetic that scrambling
+/// breaks.
+///
+/// [`with_permutation`]: ScrambledSequence::with_permutation
+///
+/// # Examples
+///
+/// ```
+/// use halton::ScrambledSequence;
+///
+/// // The Faure permutation for base 2 is the identity, so a base-2 scrambled
+/// // sequence matches the plain sequence.
+/// let mut seq = ScrambledSequence::<u16, f64, 20, 2>::faure();
+///
+/// assert_eq!(Some(0.5), seq.next());
+/// assert_eq!(Some(0.25), seq.next());
+/// ```
+#[derive(Clone)]
+pub struct ScrambledSequence<I, F, const D: usize, const B: usize> {
+    index: usize,
+    perm: [I; B],
+    f: PhantomData<F>,
+}
+
+impl<I, F, const D: usize, const B: usize> ScrambledSequence<I, F, D, B>
+where
+    I: Copy + From<u8> + PartialEq + TryFrom<usize>,
+    usize: From<I>,
+    F: Add<Output = F> + Copy + Div<Output = F> + From<I> + From<f32>,
+{
+    /// Constructs a new [`ScrambledSequence`] whose permutation is generated
+    /// from `seed` by a deterministic Fisher–Yates shuffle of `{1, …, B - 1}`
+    /// (leaving `0` fixed). The same `seed` always produces the same
+    /// permutation, so results are reproducible.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use halton::ScrambledSequence;
+    /// let a = ScrambledSequence::<u16, f64, 20, 17>::from_seed(42);
+    /// let b = ScrambledSequence::<u16, f64, 20, 17>::from_seed(42);
+    ///
+    /// assert_eq!(a.permutation(), b.permutation());
+    /// ```
+    pub fn from_seed(seed: u64) -> Self {
+        let mut perm = [I::from(0u8); B];
+        for (i, p) in perm.iter_mut().enumerate() {
+            *p = I::try_from(i).ok().unwrap();
+        }
+        // Fisher–Yates over positions 1..B, keeping perm[0] == 0.
+        let mut state = seed;
+        let mut i = B;
+        while i > 2 {
+            i -= 1;
+            let j = 1 + (next_rand(&mut state) % (i as u64)) as usize;
+            perm.swap(i, j);
+        }
+        ScrambledSequence {
+            index: 0,
+            perm,
+            f: PhantomData,
+        }
+    }
+
+    /// Constructs a new [`ScrambledSequence`] using Faure's recursively-defined
+    /// permutation for base `B`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use halton::ScrambledSequence;
+    /// let seq = ScrambledSequence::<u16, f64, 20, 5>::faure();
+    ///
+    /// assert_eq!(&[0, 3, 2, 1, 4], seq.permutation());
+    /// ```
+    pub fn faure() -> Self {
+        let mut perm = [I::from(0u8); B];
+        let faure = faure_perm::<B>();
+        for (p, v) in perm.iter_mut().zip(faure.iter()) {
+            *p = I::try_from(*v).ok().unwrap();
+        }
+        ScrambledSequence {
+            index: 0,
+            perm,
+            f: PhantomData,
+        }
+    }
+
+    /// Constructs a new [`ScrambledSequence`] from an explicit permutation.
+    ///
+    /// # Panics
+    ///
+    /// Panics unless `perm` is a true bijection of `{0, …, B - 1}` that fixes
+    /// `0`, as these are the invariants that keep the sequence in range and
+    /// equidistributed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use halton::ScrambledSequence;
+    /// let mut seq = ScrambledSequence::<u16, f64, 20, 3>::with_permutation([0, 2, 1]);
+    ///
+    /// assert_eq!(Some(0.6666666666666666), seq.next());
+    /// ```
+    pub fn with_permutation(perm: [I; B]) -> Self {
+        assert!(B == 0 || perm[0] == I::from(0u8), "permutation must fix 0");
+        let mut seen = [false; B];
+        for p in perm.iter() {
+            let v = usize::from(*p);
+            assert!(v < B && !seen[v], "permutation must be a bijection");
+            seen[v] = true;
+        }
+        ScrambledSequence {
+            index: 0,
+            perm,
+            f: PhantomData,
+        }
+    }
+
+    /// Returns the digit permutation π used by this sequence, so that scrambled
+    /// results can be reproduced or inspected.
+    #[inline]
+    pub fn permutation(&self) -> &[I; B] {
+        &self.perm
+    }
+
+    fn max() -> Option<usize> {
+        u32::try_from(D)
+            .ok()
+            .and_then(|d| B.checked_pow(d).map(|v| v - 1))
+    }
+
+    fn scrambled(&self, mut index: usize) -> F {
+        let mut digits = [I::from(0u8); D];
+        let mut len = 0;
+        while index > 0 {
+            digits[len] = self.perm[index % B];
+            index /= B;
+            len += 1;
+        }
+        let base = F::from(I::try_from(B).ok().unwrap());
+        let mut result = F::from(0.0f32);
+        for i in (0..len).rev() {
+            result = (F::from(digits[i]) + result) / base;
+        }
+        result
+    }
+}
+
+impl<I, F, const D: usize, const B: usize> Iterator for ScrambledSequence<I, F, D, B>
+where
+    I: Copy + From<u8> + PartialEq + TryFrom<usize>,
+    usize: From<I>,
+    F: Add<Output = F> + Copy + Div<Output = F> + From<I> + From<f32>,
+{
+    type Item = F;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.index + 1;
+        if let Some(max) = Self::max() {
+            if index > max {
+                return None;
+            }
+        }
+        self.index = index;
+        Some(self.scrambled(index))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if let Some(remaining) = Self::max().map(|max| max - self.index) {
+            (remaining, Some(remaining))
+        } else {
+            (0, None)
+        }
+    }
+}
+
+/// Advances the SplitMix64 generator held in `state` and returns the next
+/// value. Used to drive the Fisher–Yates shuffle in
+/// [`ScrambledSequence::from_seed`] without pulling in an external dependency.
+fn next_rand(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Builds Faure's permutation of `{0, …, B - 1}`, defined recursively from the
+/// base-2 permutation `(0, 1)`.
+fn faure_perm<const B: usize>() -> [usize; B] {
+    let mut result = [0usize; B];
+    if B <= 1 {
+        return result;
+    }
+    // The chain of sizes each permutation is built from: an even size halves,
+    // an odd size drops to the even size below it.
+    let mut chain = [0usize; 64];
+    let mut len = 0;
+    let mut n = B;
+    while n > 1 {
+        chain[len] = n;
+        len += 1;
+        n = if n.is_multiple_of(2) { n / 2 } else { n - 1 };
+    }
+    // Build up from σ_1 = (0), smallest size first.
+    let mut prev = [0usize; B];
+    for b in chain[..len].iter().copied().rev() {
+        let c = b / 2;
+        let mut cur = [0usize; B];
+        if b.is_multiple_of(2) {
+            for i in 0..c {
+                cur[i] = 2 * prev[i];
+                cur[c + i] = 2 * prev[i] + 1;
+            }
+        } else {
+            for i in 0..c {
+                cur[i] = if prev[i] >= c { prev[i] + 1 } else { prev[i] };
+            }
+            cur[c] = c;
+            for i in c..2 * c {
+                cur[i + 1] = if prev[i] >= c { prev[i] + 1 } else { prev[i] };
+            }
+        }
+        prev = cur;
+    }
+    result.copy_from_slice(&prev[..B]);
+    result
+}
+
+/// An iterator yielding a 'leaped' Halton sequence: the entries of the base's
+/// Halton sequence taken every `leap` indices, starting from `offset`. That
+/// is, it yields `number(base, offset + k·leap)` for `k = 0, 1, 2, …`.
+///
+/// The [`number`] documentation shows this pattern written out by hand;
+/// [`LeapedSequence`] packages up the index arithmetic. It gives a clean way to
+/// split one Halton stream across threads — thread `t` uses `offset = t` and a
+/// `leap` equal to the number of threads, so the threads together cover every
+/// index — or simply to thin a sequence.
+///
+/// # Examples
+///
+/// ```
+/// use halton::{number, LeapedSequence};
+///
+/// let mut seq = LeapedSequence::new(17, 409, 1);
+///
+/// assert_eq!(number(17, 1), seq.next().unwrap());
+/// assert_eq!(number(17, 410), seq.next().unwrap());
+/// ```
+#[derive(Clone)]
+pub struct LeapedSequence {
+    base: u16,
+    leap: usize,
+    index: usize,
+    done: bool,
+}
+
+impl LeapedSequence {
+    /// Constructs a new [`LeapedSequence`] for `base`, stepping `leap` indices
+    /// at a time from `offset`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `leap` is 0, as the sequence would never advance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use halton::LeapedSequence;
+    /// let mut seq = LeapedSequence::new(2, 2, 3);
+    ///
+    /// assert_eq!(Some(0.75), seq.next());
+    /// ```
+    #[inline]
+    pub fn new(base: u16, leap: usize, offset: usize) -> Self {
+        assert!(leap > 0, "leap must be greater than 0");
+        LeapedSequence {
+            base,
+            leap,
+            index: offset,
+            done: false,
+        }
+    }
+}
+
+impl Iterator for LeapedSequence {
+    type Item = f64;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let result = number(self.base, self.index);
+        match self.index.checked_add(self.leap) {
+            Some(index) => self.index = index,
+            None => self.done = true,
+        }
+        Some(result)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        let remaining = (usize::MAX - self.index) / self.leap + 1;
+        (remaining, Some(remaining))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{number, Sequence};
+    use super::{number, GenericSequence, LeapedSequence, Points, ScrambledSequence, Sequence};
     use approx::assert_relative_eq;
     use std::vec;
 
@@ -396,4 +938,206 @@ mod tests {
         let seq = Sequence::new(2);
         assert_eq!((1048575, Some(1048575)), seq.size_hint());
     }
+
+    #[test]
+    fn sequence_len() {
+        let seq = Sequence::new(2);
+        assert_eq!(1048575, seq.len());
+    }
+
+    #[test]
+    fn sequence_len_decreases_by_one_per_next() {
+        let mut seq = Sequence::new(2);
+        let mut len = seq.len();
+        for _ in 0..10 {
+            seq.next();
+            len -= 1;
+            assert_eq!(len, seq.len());
+        }
+    }
+
+    #[test]
+    fn sequence_next_back() {
+        let mut seq = GenericSequence::<u16, f64, 4>::new(2);
+        assert_relative_eq!(number(2, 15), seq.next_back().unwrap());
+        assert_relative_eq!(number(2, 14), seq.next_back().unwrap());
+        assert_relative_eq!(number(2, 13), seq.next_back().unwrap());
+    }
+
+    #[test]
+    fn sequence_rev_is_forward_reversed() {
+        use std::vec::Vec;
+
+        let forward = GenericSequence::<u16, f64, 4>::new(2).collect::<Vec<f64>>();
+        let mut reversed = GenericSequence::<u16, f64, 4>::new(2)
+            .rev()
+            .collect::<Vec<f64>>();
+        reversed.reverse();
+        assert_eq!(forward, reversed);
+    }
+
+    #[test]
+    fn sequence_front_and_back_do_not_overlap() {
+        use std::vec::Vec;
+
+        let mut seq = GenericSequence::<u16, f64, 4>::new(2);
+        let mut items = Vec::new();
+        let mut from_front = true;
+        while let Some(x) = if from_front {
+            seq.next()
+        } else {
+            seq.next_back()
+        } {
+            items.push(x);
+            from_front = !from_front;
+        }
+        // every entry of the finite window is yielded exactly once
+        assert_eq!(15, items.len());
+        let mut sorted = items.clone();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        sorted.dedup();
+        assert_eq!(15, sorted.len());
+    }
+
+    #[test]
+    fn sequence_rev_large_base_is_not_empty() {
+        // `17 ^ 20` overflows `usize`, so the window is capped at `usize::MAX`
+        // and reversing must still yield the real reversed elements rather than
+        // silently producing an empty iterator.
+        assert!(Sequence::new(17).next_back().is_some());
+        assert_eq!(5, Sequence::new(17).rev().take(5).count());
+    }
+
+    #[test]
+    fn sequence_len_large_base_does_not_panic() {
+        assert_eq!(usize::MAX, Sequence::new(17).len());
+    }
+
+    #[test]
+    fn points_base_primes() {
+        let mut points = Points::<f64, 2>::new();
+        assert_eq!(Some([0.5, 0.3333333333333333]), points.next());
+        assert_eq!(Some([0.25, 0.6666666666666666]), points.next());
+    }
+
+    #[test]
+    fn points_with_bases() {
+        let mut points = Points::<f64, 3>::with_bases([2, 3, 5]);
+        let [x, y, z] = points.next().unwrap();
+        assert_relative_eq!(0.5, x);
+        assert_relative_eq!(0.3333333333333333, y);
+        assert_relative_eq!(0.2, z);
+    }
+
+    #[test]
+    fn points_nth() {
+        let mut points = Points::<f64, 2>::new();
+        let mut stepped = Points::<f64, 2>::new();
+        stepped.next();
+        stepped.next();
+        assert_eq!(points.nth(2), stepped.next());
+    }
+
+    #[test]
+    fn points_skip() {
+        let mut points = Points::<f64, 2>::new().skip(8);
+        let [x, y] = points.next().unwrap();
+        assert_relative_eq!(0.5625, x);
+        assert_relative_eq!(0.0370370370370370, y);
+    }
+
+    #[test]
+    fn points_size_hint() {
+        let points = Points::<f64, 2>::new();
+        assert_eq!((1048575, Some(1048575)), points.size_hint());
+    }
+
+    #[test]
+    fn scrambled_faure_base_2_is_identity() {
+        let mut seq = ScrambledSequence::<u16, f64, 20, 2>::faure();
+        assert_relative_eq!(0.5, seq.next().unwrap());
+        assert_relative_eq!(0.25, seq.next().unwrap());
+        assert_relative_eq!(0.75, seq.next().unwrap());
+    }
+
+    #[test]
+    fn scrambled_faure_permutation() {
+        let seq = ScrambledSequence::<u16, f64, 20, 5>::faure();
+        assert_eq!(&[0, 3, 2, 1, 4], seq.permutation());
+    }
+
+    #[test]
+    fn scrambled_with_permutation() {
+        let mut seq = ScrambledSequence::<u16, f64, 20, 3>::with_permutation([0, 2, 1]);
+        assert_relative_eq!(0.6666666666666666, seq.next().unwrap());
+        assert_relative_eq!(0.3333333333333333, seq.next().unwrap());
+    }
+
+    #[test]
+    fn scrambled_from_seed_fixes_zero_and_is_bijection() {
+        let seq = ScrambledSequence::<u16, f64, 20, 17>::from_seed(42);
+        let perm = seq.permutation();
+        assert_eq!(0, perm[0]);
+        let mut seen = [false; 17];
+        for &p in perm.iter() {
+            assert!(!seen[p as usize]);
+            seen[p as usize] = true;
+        }
+        assert!(seen.iter().all(|&s| s));
+    }
+
+    #[test]
+    fn scrambled_from_seed_is_reproducible() {
+        let a = ScrambledSequence::<u16, f64, 20, 17>::from_seed(7);
+        let b = ScrambledSequence::<u16, f64, 20, 17>::from_seed(7);
+        assert_eq!(a.permutation(), b.permutation());
+    }
+
+    #[test]
+    fn scrambled_stays_in_range() {
+        let seq = ScrambledSequence::<u16, f64, 20, 17>::from_seed(1);
+        for x in seq.take(1000) {
+            assert!(x > 0.0 && x < 1.0);
+        }
+    }
+
+    #[test]
+    fn leaped_sequence() {
+        let mut seq = LeapedSequence::new(17, 409, 1);
+        assert_relative_eq!(number(17, 1), seq.next().unwrap());
+        assert_relative_eq!(number(17, 410), seq.next().unwrap());
+        assert_relative_eq!(number(17, 819), seq.next().unwrap());
+    }
+
+    #[test]
+    fn leaped_sequence_offset() {
+        let mut seq = LeapedSequence::new(2, 2, 3);
+        assert_relative_eq!(number(2, 3), seq.next().unwrap());
+        assert_relative_eq!(number(2, 5), seq.next().unwrap());
+    }
+
+    #[test]
+    #[should_panic]
+    fn leaped_sequence_zero_leap() {
+        LeapedSequence::new(2, 0, 1);
+    }
+
+    #[test]
+    fn leaped_sequence_partitions_stream() {
+        // Two "threads" with stride 2 and offsets 1 and 2 cover every index.
+        let a = LeapedSequence::new(2, 2, 1).take(5);
+        let b = LeapedSequence::new(2, 2, 2).take(5);
+        for (i, x) in (1..=10).zip(a.zip(b).flat_map(|(x, y)| [x, y])) {
+            assert_relative_eq!(number(2, i), x);
+        }
+    }
+
+    #[test]
+    fn leaped_sequence_size_hint() {
+        let mut seq = LeapedSequence::new(2, 3, 0);
+        let (lower, upper) = seq.size_hint();
+        assert_eq!(Some(lower), upper);
+        seq.next();
+        assert_eq!(lower - 1, seq.size_hint().0);
+    }
 }